@@ -1,6 +1,7 @@
 #[derive(PartialEq, Eq, Debug)]
 pub struct m {
-    // FIXME: 'm' packet's addr should correspond to Target::USize
+    // Parsed as a plain u64 since the packet has no knowledge of the target's
+    // address width; the dispatch site casts this to `Target::Usize`.
     pub addr: u64,
     pub len: usize,
 }
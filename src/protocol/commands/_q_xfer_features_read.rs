@@ -0,0 +1,24 @@
+/// The `qXfer:features:read:<annex>:<offset>,<length>` packet, with the
+/// `qXfer:features:read:` prefix already stripped by the caller.
+///
+/// We only serve the `target.xml` annex, so that's all this parses.
+#[derive(PartialEq, Eq, Debug)]
+pub struct QXferFeaturesRead {
+    pub offset: usize,
+    pub length: usize,
+}
+
+impl QXferFeaturesRead {
+    pub fn parse(body: &str) -> Result<Self, ()> {
+        let (annex, range) = body.split_once(':').ok_or(())?;
+        if annex != "target.xml" {
+            return Err(());
+        }
+
+        let (offset, length) = range.split_once(',').ok_or(())?;
+        let offset = usize::from_str_radix(offset, 16).map_err(drop)?;
+        let length = usize::from_str_radix(length, 16).map_err(drop)?;
+
+        Ok(QXferFeaturesRead { offset, length })
+    }
+}
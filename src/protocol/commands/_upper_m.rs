@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct M {
+    // See the note on `m::addr` - cast to `Target::Usize` at the dispatch site.
+    pub addr: u64,
+    pub len: usize,
+    pub data: Vec<u8>,
+}
+
+impl M {
+    pub fn parse(body: &str) -> Result<Self, ()> {
+        let (header, data) = body.split_once(':').ok_or(())?;
+
+        let mut header = header.split(',');
+        let addr = u64::from_str_radix(header.next().ok_or(())?, 16).map_err(drop)?;
+        let len = usize::from_str_radix(header.next().ok_or(())?, 16).map_err(drop)?;
+
+        // `len` is an arbitrary client-supplied hex value; multiply with a
+        // checked op so a huge `len` can't overflow instead of just failing
+        // the length check.
+        if len.checked_mul(2) != Some(data.len()) {
+            return Err(());
+        }
+
+        let mut bytes = Vec::with_capacity(len);
+        for chunk in data.as_bytes().chunks(2) {
+            let chunk = core::str::from_utf8(chunk).map_err(drop)?;
+            bytes.push(u8::from_str_radix(chunk, 16).map_err(drop)?);
+        }
+
+        Ok(M {
+            addr,
+            len,
+            data: bytes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_hex_payload() {
+        assert_eq!(
+            M::parse("1000,3:deadbe").unwrap(),
+            M {
+                addr: 0x1000,
+                len: 3,
+                data: alloc::vec![0xde, 0xad, 0xbe],
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_length_mismatch() {
+        assert_eq!(M::parse("0,3:dead"), Err(()));
+    }
+
+    #[test]
+    fn rejects_huge_len_without_overflow() {
+        assert_eq!(M::parse("0,ffffffffffffffff:dead"), Err(()));
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct X {
+    // See the note on `m::addr` - cast to `Target::Usize` at the dispatch site.
+    pub addr: u64,
+    pub len: usize,
+    pub data: Vec<u8>,
+}
+
+impl X {
+    pub fn parse(body: &str) -> Result<Self, ()> {
+        let (header, data) = body.split_once(':').ok_or(())?;
+
+        let mut header = header.split(',');
+        let addr = u64::from_str_radix(header.next().ok_or(())?, 16).map_err(drop)?;
+        let len = usize::from_str_radix(header.next().ok_or(())?, 16).map_err(drop)?;
+
+        let data = unescape(data.as_bytes());
+        if data.len() != len {
+            return Err(());
+        }
+
+        Ok(X { addr, len, data })
+    }
+}
+
+/// Un-escape the binary data of an `X` packet: a `0x7d` byte is followed by
+/// the real byte XORed with `0x20`.
+fn unescape(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut iter = data.iter().copied();
+    while let Some(b) = iter.next() {
+        match b {
+            0x7d => {
+                if let Some(escaped) = iter.next() {
+                    out.push(escaped ^ 0x20);
+                }
+            }
+            b => out.push(b),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unescaped_payload() {
+        assert_eq!(
+            X::parse("1000,3:abc").unwrap(),
+            X {
+                addr: 0x1000,
+                len: 3,
+                data: alloc::vec![b'a', b'b', b'c'],
+            }
+        );
+    }
+
+    #[test]
+    fn unescapes_0x7d_sequences() {
+        // 0x7d 0x5d is the escaped form of the escape byte itself (0x5d ^ 0x20 = 0x7d)
+        assert_eq!(
+            unescape(&[0x01, 0x7d, 0x5d, 0x02]),
+            alloc::vec![0x01, 0x7d, 0x02]
+        );
+    }
+
+    #[test]
+    fn rejects_length_mismatch() {
+        // two escaped bytes collapse down to one unescaped byte, so the
+        // declared length of 2 doesn't match the single byte we actually got
+        // (`}` is 0x7d and `]` is 0x5d)
+        assert_eq!(X::parse("0,2:}]"), Err(()));
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(PartialEq, Eq, Debug)]
+pub struct z {
+    pub kind: u8,
+    pub addr: u64,
+    pub bp_len: u64,
+}
+
+impl z {
+    pub fn parse(body: &str) -> Result<Self, ()> {
+        let mut body = body.split(',');
+        let kind = u8::from_str_radix(body.next().ok_or(())?, 16).map_err(drop)?;
+        let addr = u64::from_str_radix(body.next().ok_or(())?, 16).map_err(drop)?;
+        let bp_len = u64::from_str_radix(body.next().ok_or(())?, 16).map_err(drop)?;
+
+        Ok(z { kind, addr, bp_len })
+    }
+}
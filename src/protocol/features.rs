@@ -0,0 +1,60 @@
+use alloc::string::String;
+
+use crate::Target;
+
+/// Default value advertised for `PacketSize`, in bytes.
+///
+/// This bounds how large a single incoming packet body is allowed to grow in
+/// [`GdbStub::recv_packet`](crate::stub::GdbStub), and is reported to the
+/// client so it never sends anything larger.
+pub(crate) const DEFAULT_PACKET_SIZE: usize = 0x3fff;
+
+/// Tracks which optional RSP features this stub advertises to GDB in response
+/// to `qSupported`.
+///
+/// Everything here that's backed by an optional `Target` extension is only
+/// advertised when that extension is actually present - a client that takes
+/// our word for it will send packets like `bc`/`bs` or `vCont` and expect
+/// them to be handled, not silently dropped.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StubFeatures {
+    packet_size: usize,
+}
+
+impl Default for StubFeatures {
+    fn default() -> Self {
+        StubFeatures {
+            packet_size: DEFAULT_PACKET_SIZE,
+        }
+    }
+}
+
+impl StubFeatures {
+    /// Maximum size (in bytes) of a single packet body we're willing to
+    /// buffer, as advertised via `PacketSize`.
+    pub(crate) fn packet_size(&self) -> usize {
+        self.packet_size
+    }
+
+    /// Render the `qSupported` reply body advertising this stub's feature
+    /// set, probing `target` for which optional extensions back the
+    /// capability-dependent features.
+    ///
+    /// `features` is the client's own `;`-separated feature list (currently
+    /// unused beyond being parsed, since this stub's reply doesn't yet
+    /// depend on anything GDB offers).
+    pub(crate) fn reply<T: Target>(&self, target: &mut T, features: &str) -> String {
+        let _client_features = features.split(';');
+
+        let mut reply = alloc::format!("PacketSize={:x};QStartNoAckMode+", self.packet_size);
+
+        if target.target_description_xml().is_some() {
+            reply.push_str(";qXfer:features:read+");
+        }
+        if target.support_reverse_exec().is_some() {
+            reply.push_str(";ReverseStep+;ReverseContinue+");
+        }
+
+        reply
+    }
+}
@@ -1,10 +1,13 @@
 use alloc::format;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write as _;
 
 use log::*;
 
 use crate::{
-    protocol::{Command, Packet, ResponseWriter},
+    protocol::{features::StubFeatures, Command, Packet, ResponseWriter},
+    target::{HwBreakKind, Tid},
     Connection, Error, Target, TargetState,
 };
 
@@ -14,11 +17,64 @@ enum ExecState {
     Exit,
 }
 
+/// Which way the target is executing: set to `Reverse` by `bc` (reverse
+/// continue), and back to `Forward` whenever the run loop reports a stop.
+/// `bs` (reverse step) steps back directly and leaves it alone.
+#[derive(PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Reverse,
+}
+
+/// State of the incremental, resumable packet reader.
+///
+/// `recv_packet` consumes a single byte per call to the underlying
+/// `Connection` and advances through these states, stopping (without losing
+/// progress) whenever `read_nonblocking` has nothing for us yet. While the
+/// target is `Running`, this lets `run` keep stepping it between the bytes
+/// of a partially received packet. While `Paused`, bytes are read with the
+/// blocking `Connection::read`, and `run` itself only returns once the
+/// target halts or the session exits, so it does not hand control back to
+/// an outside poll loop between packets.
+enum ReaderState {
+    /// Waiting for the leading `$` (or a bare `+`/`-` ack byte).
+    AwaitHeader,
+    /// Accumulating packet body bytes, up to the trailing `#`.
+    InBody,
+    /// Body is complete; waiting for the first checksum hex digit.
+    AwaitChecksum0,
+    /// Waiting for the second checksum hex digit, having already seen `hi`.
+    AwaitChecksum1 { hi: u8 },
+}
+
+/// How many times we'll resend our last response after a `Nack` before
+/// giving up on the session.
+const MAX_RETRANSMITS: usize = 5;
+
 /// [`GdbStub`] maintains the state of a GDB remote debugging session, including
 /// the underlying transport.
 pub struct GdbStub<T: Target, C: Connection> {
     conn: C,
     exec_state: ExecState,
+    features: StubFeatures,
+    /// `(addr, len)` of the hardware breakpoints/watchpoints currently
+    /// installed, tracked so we can enforce
+    /// `Target::Breakpoints::max_hw_breakpoints`. `len` is kept alongside
+    /// `addr` since differently-sized watchpoints can share an address.
+    hw_breakpoints: Vec<(u64, u64)>,
+    /// The thread GDB selected via the most recent `H` packet, if any.
+    current_tid: Option<Tid>,
+    /// Set once `QStartNoAckMode` is acknowledged; disables all `+`/`-`
+    /// handshaking for the rest of the session.
+    no_ack_mode: bool,
+    /// The last response we sent, kept around so we can resend it verbatim
+    /// if the peer replies with a `Nack`.
+    last_response: String,
+    /// How many times we've resent `last_response` in a row.
+    retransmit_count: usize,
+    reader_state: ReaderState,
+    packet_buffer: Vec<u8>,
+    direction: Direction,
     _target: core::marker::PhantomData<T>,
 }
 
@@ -27,6 +83,15 @@ impl<T: Target, C: Connection> GdbStub<T, C> {
         GdbStub {
             conn,
             exec_state: ExecState::Paused,
+            features: StubFeatures::default(),
+            hw_breakpoints: Vec::new(),
+            current_tid: None,
+            no_ack_mode: false,
+            last_response: String::new(),
+            retransmit_count: 0,
+            reader_state: ReaderState::AwaitHeader,
+            packet_buffer: Vec::new(),
+            direction: Direction::Forward,
             _target: core::marker::PhantomData,
         }
     }
@@ -36,77 +101,337 @@ impl<T: Target, C: Connection> GdbStub<T, C> {
         target: &mut T,
         command: Command,
     ) -> Result<(), Error<T::Error, C::Error>> {
-        // Acknowledge the command
-        self.conn.write(b'+').map_err(Error::Connection)?;
+        // `bc`/`bs` don't fit the uniform "one reply per command" shape below:
+        // `bc` just flips the run direction and resumes, while `bs` needs to
+        // report a stop the same way the run loop does.
+        //
+        // Reverse execution is only reachable through these two packets.
+        // `vCont;R` (GDB's alternate, range-stepping-capable way to ask for
+        // reverse execution) is intentionally not handled: there's no
+        // `Command::VCont` support of any kind in this stub yet, reverse or
+        // otherwise, so adding just the reverse action would be half a
+        // feature. Left for a follow-up that adds `vCont` itself.
+        match command {
+            Command::Bc => {
+                return match target.support_reverse_exec() {
+                    Some(_) => {
+                        self.direction = Direction::Reverse;
+                        self.exec_state = ExecState::Running;
+                        Ok(())
+                    }
+                    // Unlike `bs`, `bc` doesn't report a stop of its own - the
+                    // run loop does that once it actually stops - but a client
+                    // still needs *some* reply rather than hanging forever.
+                    None => self.send_response(""),
+                };
+            }
+            Command::Bs => {
+                return match target.support_reverse_exec() {
+                    Some(rev) => {
+                        // `Halted` (no reverse history left) is reported as a
+                        // stop too, just as the run loop does in reverse
+                        rev.step_back().map_err(Error::TargetError)?;
+                        self.report_stop()
+                    }
+                    None => self.send_response(""),
+                };
+            }
+            _ => {}
+        }
 
-        let mut res = ResponseWriter::new(&mut self.conn);
+        let reply = match command {
+            Command::QSupported(features) => self.features.reply(target, features),
+            Command::QStartNoAckMode => {
+                self.no_ack_mode = true;
+                String::from("OK")
+            }
+            Command::H { op: _, tid } => {
+                // A thread id of 0 means "pick any thread"; we just fall back
+                // to whatever the target considers current.
+                self.current_tid = if tid == 0 { None } else { Some(Tid(tid)) };
 
-        match command {
-            Command::QSupported(_features) => {
-                // TODO: actually respond with own feature set
+                match (self.current_tid, target.support_multithread()) {
+                    (Some(tid), Some(mt)) => match mt.set_current_thread(tid) {
+                        Ok(()) => String::from("OK"),
+                        Err(_) => String::from("E01"),
+                    },
+                    _ => String::from("OK"),
+                }
+            }
+            Command::QfThreadInfo => match target.support_multithread() {
+                None => String::from("l"),
+                Some(mt) => {
+                    let mut tids = Vec::new();
+                    mt.list_threads(&mut |tid| tids.push(tid))
+                        .map_err(Error::TargetError)?;
+
+                    if tids.is_empty() {
+                        String::from("l")
+                    } else {
+                        let mut reply = String::from("m");
+                        for (i, tid) in tids.iter().enumerate() {
+                            if i > 0 {
+                                reply.push(',');
+                            }
+                            let _ = write!(reply, "{:x}", tid.0);
+                        }
+                        reply
+                    }
+                }
+            },
+            // We always report the full thread list in one qfThreadInfo
+            // reply, so every subsequent qsThreadInfo just ends the list.
+            Command::QsThreadInfo => String::from("l"),
+            Command::QC => match target.support_multithread() {
+                None => String::from("QC0"),
+                Some(mt) => {
+                    let tid = mt.current_thread().map_err(Error::TargetError)?;
+                    format!("QC{:x}", tid.0)
+                }
+            },
+            // `m.len` is client-controlled and parsed as an arbitrary hex `usize`;
+            // reject anything over the negotiated PacketSize before allocating so a crafted
+            // `m0,ffffffffffffffff` can't trigger a huge/failing allocation.
+            Command::m(m) if m.len > self.features.packet_size() => String::from("E01"),
+            Command::m(m) => match Self::cast_addr(m.addr) {
+                Ok(addr) => {
+                    let mut data = alloc::vec![0; m.len];
+                    match target.read_addrs(addr, &mut data) {
+                        Ok(()) => {
+                            let mut reply = String::with_capacity(data.len() * 2);
+                            for byte in data {
+                                let _ = write!(reply, "{:02x}", byte);
+                            }
+                            reply
+                        }
+                        Err(_) => String::from("E01"),
+                    }
+                }
+                Err(()) => String::from("E01"),
+            },
+            Command::M(m) => match Self::cast_addr(m.addr) {
+                Ok(addr) => match target.write_addrs(addr, &m.data) {
+                    Ok(()) => String::from("OK"),
+                    Err(_) => String::from("E01"),
+                },
+                Err(()) => String::from("E01"),
+            },
+            Command::X(x) => match Self::cast_addr(x.addr) {
+                Ok(addr) => match target.write_addrs(addr, &x.data) {
+                    Ok(()) => String::from("OK"),
+                    Err(_) => String::from("E01"),
+                },
+                Err(()) => String::from("E01"),
+            },
+            Command::Z(z) if z.kind == 0 => match target.support_breakpoints() {
+                None => String::new(), // unsupported: empty reply per the RSP spec
+                Some(bp) => match bp.add_sw_breakpoint(z.addr) {
+                    Ok(true) => String::from("OK"),
+                    Ok(false) | Err(_) => String::from("E01"),
+                },
+            },
+            Command::Z(z) => match (target.support_breakpoints(), hw_break_kind(z.kind)) {
+                (Some(_), None) => String::from("E01"), // unrecognized watchpoint type
+                (None, _) => String::new(),
+                (Some(bp), Some(kind)) => {
+                    let result = if self.hw_breakpoints.len() >= bp.max_hw_breakpoints() {
+                        Ok(false)
+                    } else {
+                        bp.add_hw_breakpoint(z.addr, z.bp_len, kind)
+                    };
+
+                    match result {
+                        Ok(true) => {
+                            self.hw_breakpoints.push((z.addr, z.bp_len));
+                            String::from("OK")
+                        }
+                        Ok(false) | Err(_) => String::from("E01"),
+                    }
+                }
+            },
+            Command::z(z) if z.kind == 0 => match target.support_breakpoints() {
+                None => String::new(),
+                Some(bp) => match bp.remove_sw_breakpoint(z.addr) {
+                    Ok(true) => String::from("OK"),
+                    Ok(false) | Err(_) => String::from("E01"),
+                },
+            },
+            Command::z(z) => match (target.support_breakpoints(), hw_break_kind(z.kind)) {
+                (Some(_), None) => String::from("E01"),
+                (None, _) => String::new(),
+                (Some(bp), Some(kind)) => {
+                    let result = bp.remove_hw_breakpoint(z.addr, z.bp_len, kind);
+
+                    match result {
+                        Ok(true) => {
+                            // drop just the one removed entry; another kind may
+                            // still be installed at the same (addr, len)
+                            let removed = (z.addr, z.bp_len);
+                            if let Some(i) = self.hw_breakpoints.iter().position(|&e| e == removed) {
+                                self.hw_breakpoints.swap_remove(i);
+                            }
+                            String::from("OK")
+                        }
+                        Ok(false) | Err(_) => String::from("E01"),
+                    }
+                }
+            },
+            Command::QXferFeaturesRead(q) => match target.target_description_xml() {
+                None => String::new(), // empty reply: annex unsupported
+                Some(xml) => xfer_chunk(xml.as_bytes(), q.offset, q.length),
+            },
+            Command::Unknown => {
+                trace!("Unknown command");
+                String::new()
             }
-            Command::H { .. } => {
-                // TODO: implement me
-                res.write_str("OK").map_err(Error::Connection)?;
+            c => {
+                trace!("Unimplemented command: {:#?}", c);
+                String::new()
             }
-            Command::Unknown => trace!("Unknown command"),
-            c => trace!("Unimplemented command: {:#?}", c),
+        };
+
+        self.send_response(&reply)
+    }
+
+    /// Send a minimal stop-reply packet reporting a trap (e.g. a breakpoint
+    /// hit) to GDB, attributing the stop to the currently selected thread
+    /// when the target is multi-threaded.
+    fn report_stop(&mut self) -> Result<(), Error<T::Error, C::Error>> {
+        let mut reply = String::from("T05");
+        if let Some(tid) = self.current_tid {
+            let _ = write!(reply, "thread:{:x};", tid.0);
         }
 
+        self.send_response(&reply)
+    }
+
+    /// Frame and send `reply` as our response, remembering it so it can be
+    /// resent verbatim if the peer NACKs it.
+    fn send_response(&mut self, reply: &str) -> Result<(), Error<T::Error, C::Error>> {
+        self.last_response.clear();
+        self.last_response.push_str(reply);
+        self.retransmit_count = 0;
+        self.resend_last_response()
+    }
+
+    /// (Re-)send `self.last_response` over the wire.
+    ///
+    /// Unlike `recv_packet`, this still writes and flushes `ResponseWriter` in
+    /// one blocking call - there's no resumable state machine on the write
+    /// side yet, so calling this from a non-blocking `run` loop can still
+    /// stall mid-response on a connection whose writes themselves block.
+    fn resend_last_response(&mut self) -> Result<(), Error<T::Error, C::Error>> {
+        let mut res = ResponseWriter::new(&mut self.conn);
+        res.write_str(&self.last_response)
+            .map_err(Error::Connection)?;
         res.flush().map_err(Error::Connection)
     }
 
-    fn recv_packet<'a, 'b>(
-        &'a mut self,
-        packet_buffer: &'b mut Vec<u8>,
-    ) -> Result<Option<Packet<'b>>, Error<T::Error, C::Error>> {
-        let header_byte = match self.exec_state {
-            // block waiting for a gdb command
-            ExecState::Paused => self.conn.read().map(Some),
-            ExecState::Running => self.conn.read_nonblocking(),
+    /// Called when the peer NACKs our last response. Resends it, up to
+    /// `MAX_RETRANSMITS` times, after which the session is considered dead.
+    fn retransmit(&mut self) -> Result<(), Error<T::Error, C::Error>> {
+        self.retransmit_count += 1;
+        if self.retransmit_count > MAX_RETRANSMITS {
+            return Err(Error::TooManyRetransmits);
+        }
+        self.resend_last_response()
+    }
+
+    /// Cast a packet-parsed `u64` address down to the target's native
+    /// address width.
+    fn cast_addr(addr: u64) -> Result<T::Usize, ()> {
+        T::Usize::try_from(addr).map_err(drop)
+    }
+
+    /// Read a single byte, blocking only while `Paused` (there's nothing
+    /// else to do anyway); while `Running`, use the non-blocking primitive so
+    /// we never stall the target's step loop.
+    fn read_byte(&mut self) -> Result<Option<u8>, Error<T::Error, C::Error>> {
+        match self.exec_state {
+            ExecState::Paused => self.conn.read().map(Some).map_err(Error::Connection),
+            ExecState::Running => self.conn.read_nonblocking().map_err(Error::Connection),
             ExecState::Exit => unreachable!(),
-        };
+        }
+    }
 
-        match header_byte {
-            Ok(None) => Ok(None), // no incoming message
-            Ok(Some(header_byte)) => {
-                packet_buffer.clear();
-                packet_buffer.push(header_byte);
-                if header_byte == b'$' {
-                    // read the packet body
-                    loop {
-                        match self.conn.read().map_err(Error::Connection)? {
-                            b'#' => break,
-                            x => packet_buffer.push(x),
+    /// Drive the [`ReaderState`] machine forward by as many bytes as are
+    /// currently available, returning `Ok(None)` (without losing progress)
+    /// the moment a byte isn't ready yet.
+    fn recv_packet(&mut self) -> Result<Option<Packet<'_>>, Error<T::Error, C::Error>> {
+        loop {
+            let byte = match self.read_byte()? {
+                Some(byte) => byte,
+                None => return Ok(None),
+            };
+
+            match self.reader_state {
+                ReaderState::AwaitHeader => {
+                    self.packet_buffer.clear();
+                    self.packet_buffer.push(byte);
+                    if byte == b'$' {
+                        self.reader_state = ReaderState::InBody;
+                    } else {
+                        // a bare '+'/'-' ack byte is a complete "packet" on its own
+                        return Some(Packet::from_buf(&mut self.packet_buffer))
+                            .transpose()
+                            .map_err(|e| Error::PacketParse(format!("{:?}", e)));
+                    }
+                }
+                ReaderState::InBody => {
+                    if byte == b'#' {
+                        self.packet_buffer.push(byte);
+                        self.reader_state = ReaderState::AwaitChecksum0;
+                    } else {
+                        // bounded by the PacketSize we advertised in qSupported, so a
+                        // misbehaving peer can't grow the buffer unboundedly (the
+                        // buffer also holds the leading '$', hence `>` not `>=`)
+                        if self.packet_buffer.len() > self.features.packet_size() {
+                            return Err(Error::PacketBufferOverflow);
                         }
+                        self.packet_buffer.push(byte);
                     }
-                    // append the # char
-                    packet_buffer.push(b'#');
-                    // and finally, read the checksum as well
-                    packet_buffer.push(self.conn.read().map_err(Error::Connection)?);
-                    packet_buffer.push(self.conn.read().map_err(Error::Connection)?);
                 }
+                ReaderState::AwaitChecksum0 => {
+                    self.packet_buffer.push(byte);
+                    self.reader_state = ReaderState::AwaitChecksum1 { hi: byte };
+                }
+                ReaderState::AwaitChecksum1 { hi } => {
+                    self.packet_buffer.push(byte);
+                    self.reader_state = ReaderState::AwaitHeader;
+
+                    if !self.no_ack_mode {
+                        // body is everything between the leading '$' and the trailing '#'
+                        let body = &self.packet_buffer[1..self.packet_buffer.len() - 3];
+                        let actual = body.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+                        let expected = decode_hex_byte(hi, byte);
 
-                Some(Packet::from_buf(packet_buffer))
-                    .transpose()
-                    .map_err(|e| Error::PacketParse(format!("{:?}", e)))
+                        if expected != Some(actual) {
+                            self.conn.write(b'-').map_err(Error::Connection)?;
+                            return Ok(None);
+                        }
+
+                        self.conn.write(b'+').map_err(Error::Connection)?;
+                    }
+
+                    return Some(Packet::from_buf(&mut self.packet_buffer))
+                        .transpose()
+                        .map_err(|e| Error::PacketParse(format!("{:?}", e)));
+                }
             }
-            Err(e) => Err(Error::Connection(e)),
         }
     }
 
     /// Runs the target in a loop, with debug checks between each call to `target.step()`
     pub fn run(&mut self, target: &mut T) -> Result<TargetState, Error<T::Error, C::Error>> {
-        let mut packet_buffer = Vec::new();
         let mut mem_accesses = Vec::new();
 
         loop {
             // Handle any incoming GDB packets
-            match self.recv_packet(&mut packet_buffer)? {
+            match self.recv_packet()? {
                 None => {}
                 Some(packet) => match packet {
                     Packet::Ack => {}
-                    Packet::Nack => unimplemented!(),
+                    Packet::Nack => self.retransmit()?,
                     Packet::Command(command) => {
                         self.handle_command(target, command)?;
                     }
@@ -116,12 +441,52 @@ impl<T: Target, C: Connection> GdbStub<T, C> {
             match self.exec_state {
                 ExecState::Paused => {}
                 ExecState::Running => {
-                    let target_state = target
-                        .step(|access| mem_accesses.push(access))
-                        .map_err(Error::TargetError)?;
+                    let target_state = if self.direction == Direction::Reverse {
+                        match target.support_reverse_exec() {
+                            Some(rev) => rev.step_back().map_err(Error::TargetError)?,
+                            None => TargetState::Halted,
+                        }
+                    } else {
+                        let state = match (self.current_tid, target.support_multithread()) {
+                            (Some(tid), Some(mt)) => {
+                                mt.step_thread(tid).map_err(Error::TargetError)?
+                            }
+                            _ => target
+                                .step(|access| mem_accesses.push(access))
+                                .map_err(Error::TargetError)?,
+                        };
 
-                    if target_state == TargetState::Halted {
-                        return Ok(TargetState::Halted);
+                        // Refresh the checkpoint after every forward step, so a
+                        // backend whose `step_back` is itself implemented in
+                        // terms of `restore_checkpoint` (per `ReverseExec`'s
+                        // doc comment) always has somewhere recent to rewind
+                        // to. The stub itself never calls `restore_checkpoint`
+                        // directly - that's an implementation detail of
+                        // `step_back`, not something the run loop should
+                        // second-guess.
+                        if let Some(rev) = target.support_reverse_exec() {
+                            rev.checkpoint().map_err(Error::TargetError)?;
+                        }
+
+                        state
+                    };
+
+                    match target_state {
+                        // Ran out of recorded reverse history: pause right
+                        // where `step_back` left the target (the oldest state
+                        // it could reach) instead of rewinding further.
+                        TargetState::Halted if self.direction == Direction::Reverse => {
+                            self.exec_state = ExecState::Paused;
+                            self.direction = Direction::Forward;
+                            self.report_stop()?;
+                        }
+                        TargetState::Halted => return Ok(TargetState::Halted),
+                        TargetState::Breakpoint(_addr) => {
+                            self.exec_state = ExecState::Paused;
+                            self.direction = Direction::Forward;
+                            self.report_stop()?;
+                        }
+                        TargetState::Running => {}
                     };
                 }
                 ExecState::Exit => {
@@ -131,3 +496,93 @@ impl<T: Target, C: Connection> GdbStub<T, C> {
         }
     }
 }
+
+/// Map a `Z`/`z` packet's `type` field to the [`HwBreakKind`] it names.
+/// `0` (software breakpoint) isn't a hardware kind and is handled by its own
+/// match arm before this is ever called; anything outside `1..=4` isn't a
+/// type GDB defines, so it's `None`.
+fn hw_break_kind(kind: u8) -> Option<HwBreakKind> {
+    match kind {
+        1 => Some(HwBreakKind::Exec),
+        2 => Some(HwBreakKind::Write),
+        3 => Some(HwBreakKind::Read),
+        4 => Some(HwBreakKind::ReadWrite),
+        _ => None,
+    }
+}
+
+/// Decode a two-digit hex checksum (as found trailing a `$...#xx` packet)
+/// into its byte value.
+fn decode_hex_byte(hi: u8, lo: u8) -> Option<u8> {
+    let hi = (hi as char).to_digit(16)?;
+    let lo = (lo as char).to_digit(16)?;
+    Some((hi * 16 + lo) as u8)
+}
+
+/// Slice out the `qXfer`-style chunk of `bytes` starting at `offset`, up to
+/// `length` bytes, framed with the `m`/`l` prefix GDB expects (`l` marking
+/// the final chunk).
+///
+/// `offset`/`length` are both client-controlled, so the end index is
+/// computed with a saturating add rather than trusting the sum not to
+/// overflow.
+fn xfer_chunk(bytes: &[u8], offset: usize, length: usize) -> String {
+    if offset >= bytes.len() {
+        return String::from("l");
+    }
+
+    let end = offset.saturating_add(length).min(bytes.len());
+    // `offset`/`length` come from the client; they may not land on a char
+    // boundary, so fall back to a lossy decode rather than panic
+    let chunk = String::from_utf8_lossy(&bytes[offset..end]);
+    let prefix = if end >= bytes.len() { 'l' } else { 'm' };
+    format!("{}{}", prefix, chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_hex_byte_parses_valid_digits() {
+        assert_eq!(decode_hex_byte(b'a', b'f'), Some(0xaf));
+        assert_eq!(decode_hex_byte(b'0', b'0'), Some(0x00));
+    }
+
+    #[test]
+    fn decode_hex_byte_rejects_non_hex() {
+        assert_eq!(decode_hex_byte(b'z', b'0'), None);
+    }
+
+    #[test]
+    fn xfer_chunk_splits_across_calls() {
+        let data = b"0123456789";
+        assert_eq!(xfer_chunk(data, 0, 4), "m0123");
+        assert_eq!(xfer_chunk(data, 4, 4), "m4567");
+        assert_eq!(xfer_chunk(data, 8, 4), "l89");
+    }
+
+    #[test]
+    fn xfer_chunk_reports_end_of_data_past_len() {
+        assert_eq!(xfer_chunk(b"abc", 3, 4), "l");
+    }
+
+    #[test]
+    fn xfer_chunk_does_not_overflow_on_huge_length() {
+        assert_eq!(xfer_chunk(b"abc", 1, usize::MAX), "lbc");
+    }
+
+    #[test]
+    fn hw_break_kind_maps_known_types() {
+        assert_eq!(hw_break_kind(1), Some(HwBreakKind::Exec));
+        assert_eq!(hw_break_kind(2), Some(HwBreakKind::Write));
+        assert_eq!(hw_break_kind(3), Some(HwBreakKind::Read));
+        assert_eq!(hw_break_kind(4), Some(HwBreakKind::ReadWrite));
+    }
+
+    #[test]
+    fn hw_break_kind_rejects_unknown_types() {
+        assert_eq!(hw_break_kind(0), None);
+        assert_eq!(hw_break_kind(5), None);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::TargetState;
+
+/// A single memory access performed while stepping the target, reported back
+/// to the stub through [`Target::step`]'s callback. The stub collects these
+/// but does not forward them to GDB.
+pub enum Access<U> {
+    Read(U),
+    Write(U),
+}
+
+/// The set of operations [`GdbStub`](crate::stub::GdbStub) needs from the
+/// thing being debugged.
+///
+/// Implement this trait for your emulator/hypervisor/whatever is running the
+/// guest code, then hand it to [`GdbStub::run`](crate::stub::GdbStub::run).
+pub trait Target {
+    /// The error type returned by the target's own fallible operations.
+    type Error;
+
+    /// The target's native address width, used for register and memory
+    /// operations.
+    type Usize: Copy + core::convert::TryFrom<u64> + Into<u64>;
+
+    /// Step the target by a single instruction, reporting any memory
+    /// accesses made along the way via `mem_access_callback`.
+    fn step(
+        &mut self,
+        mem_access_callback: impl FnMut(Access<Self::Usize>),
+    ) -> Result<TargetState, Self::Error>;
+
+    /// Read `data.len()` bytes of guest memory starting at `start_addr`.
+    fn read_addrs(&mut self, start_addr: Self::Usize, data: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Write `data` into guest memory starting at `start_addr`.
+    fn write_addrs(&mut self, start_addr: Self::Usize, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// The target description XML (register groups, names, bitsizes, and
+    /// architecture) served over `qXfer:features:read:target.xml`.
+    ///
+    /// Returning `None` means GDB gets no description and falls back to
+    /// whatever it already assumes about the architecture.
+    fn target_description_xml(&self) -> Option<&str> {
+        None
+    }
+
+    /// If this target supports breakpoints, return a handle to the
+    /// [`Breakpoints`] extension. Defaults to `None`.
+    fn support_breakpoints(&mut self) -> Option<&mut dyn Breakpoints<Error = Self::Error>> {
+        None
+    }
+
+    /// If this target exposes more than one thread, return a handle to the
+    /// [`MultiThread`] extension. Defaults to `None`.
+    fn support_multithread(&mut self) -> Option<&mut dyn MultiThread<Error = Self::Error>> {
+        None
+    }
+
+    /// If this target can run backwards, return a handle to the
+    /// [`ReverseExec`] extension. Defaults to `None`.
+    fn support_reverse_exec(&mut self) -> Option<&mut dyn ReverseExec<Error = Self::Error>> {
+        None
+    }
+}
+
+/// Optional `Target` extension backing GDB's `reverse-continue` and
+/// `reverse-step` commands.
+///
+/// Implementations can either genuinely step the machine backward one
+/// instruction, or restore a previously recorded checkpoint - whichever
+/// fits the backend.
+pub trait ReverseExec {
+    type Error;
+
+    /// Step the target backward by a single instruction.
+    fn step_back(&mut self) -> Result<TargetState, Self::Error>;
+
+    /// Snapshot the current machine state, to later be restored by
+    /// [`ReverseExec::restore_checkpoint`].
+    fn checkpoint(&mut self) -> Result<(), Self::Error>;
+
+    /// Rewind the machine to the most recent [`ReverseExec::checkpoint`].
+    fn restore_checkpoint(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Which hardware facility a `Z`/`z` packet's `type` field (`1`-`4`) is
+/// asking for: a hardware execution breakpoint, or a watchpoint that traps on
+/// some combination of reads and writes.
+///
+/// These program distinct debug facilities on real hardware (e.g. x86 DR7's
+/// R/W bits), so a backend needs to know which one it's being asked to set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HwBreakKind {
+    /// `Z1`/`z1`: trap on instruction fetch.
+    Exec,
+    /// `Z2`/`z2`: trap on write.
+    Write,
+    /// `Z3`/`z3`: trap on read.
+    Read,
+    /// `Z4`/`z4`: trap on read or write.
+    ReadWrite,
+}
+
+/// Optional `Target` extension exposing software and hardware
+/// breakpoint/watchpoint support, dispatched from the `Z`/`z` packets.
+pub trait Breakpoints {
+    type Error;
+
+    /// Insert a software breakpoint at `addr`. Returns `false` if one was
+    /// already set there.
+    fn add_sw_breakpoint(&mut self, addr: u64) -> Result<bool, Self::Error>;
+
+    /// Remove a software breakpoint at `addr`. Returns `false` if none was
+    /// set there.
+    fn remove_sw_breakpoint(&mut self, addr: u64) -> Result<bool, Self::Error>;
+
+    /// Insert a hardware breakpoint/watchpoint of the given `kind`, spanning
+    /// `len` bytes starting at `addr`. Returns `false` if one was already set
+    /// there.
+    ///
+    /// `len` distinguishes differently-sized watchpoints at the same `addr`
+    /// (e.g. a 1-byte and a 4-byte watchpoint don't collide).
+    fn add_hw_breakpoint(
+        &mut self,
+        addr: u64,
+        len: u64,
+        kind: HwBreakKind,
+    ) -> Result<bool, Self::Error>;
+
+    /// Remove the hardware breakpoint/watchpoint of the given `kind`,
+    /// spanning `len` bytes starting at `addr`. Returns `false` if none was
+    /// set there.
+    fn remove_hw_breakpoint(
+        &mut self,
+        addr: u64,
+        len: u64,
+        kind: HwBreakKind,
+    ) -> Result<bool, Self::Error>;
+
+    /// The maximum number of hardware breakpoints/watchpoints the backend
+    /// can have installed at once (e.g. a hypervisor's guest-debug HW-bp
+    /// count).
+    fn max_hw_breakpoints(&self) -> usize;
+}
+
+/// Identifies a single thread (e.g. a vCPU) within a multi-threaded target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tid(pub u64);
+
+/// Optional `Target` extension for targets that expose more than one thread
+/// of execution (e.g. an SMP guest, where each vCPU is a thread).
+pub trait MultiThread {
+    type Error;
+
+    /// Invoke `callback` once for each currently active thread.
+    fn list_threads(&mut self, callback: &mut dyn FnMut(Tid)) -> Result<(), Self::Error>;
+
+    /// The thread GDB should be talking to right now.
+    fn current_thread(&mut self) -> Result<Tid, Self::Error>;
+
+    /// Make `tid` the thread that subsequent `step_thread` calls (and memory
+    /// accesses, if the target scopes those per-thread) should target, in
+    /// response to GDB's `H` packet.
+    fn set_current_thread(&mut self, tid: Tid) -> Result<(), Self::Error>;
+
+    /// Step a single thread by one instruction.
+    fn step_thread(&mut self, tid: Tid) -> Result<TargetState, Self::Error>;
+}